@@ -18,5 +18,15 @@ pub enum Error {
     FromTrxKindsError,
     IncreMerkleError,
     InvalidLength,
+    /// A header's `block_num`/`previous` does not chain onto the validator's head block.
+    BrokenParentLink,
+    /// Bubbled up from signature verification or digesting the header in the `primitives` crate.
+    BlockHeader(primitives::Error),
+}
+
+impl From<primitives::Error> for Error {
+    fn from(err: primitives::Error) -> Self {
+        Error::BlockHeader(err)
+    }
 }
 
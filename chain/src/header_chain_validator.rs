@@ -0,0 +1,173 @@
+use primitives::{Checksum256, ProducerScheduleV2, SignedBlockHeader};
+
+use crate::{Error, Result};
+
+/// Folds a stream of signed block headers, enforcing parent/block-num
+/// continuity and producer signatures, and rotating to a pending producer
+/// schedule once its `schedule_version` takes effect.
+///
+/// This is the light-client counterpart to a full node's fork database: it
+/// only keeps the head block id/num and the active/pending schedules, not
+/// the chain of blocks itself.
+#[derive(Clone, Debug)]
+pub struct HeaderChainValidator {
+    head_block_num: u32,
+    head_block_id: Checksum256,
+    active_schedule: ProducerScheduleV2,
+    pending_schedule: Option<ProducerScheduleV2>,
+}
+
+impl HeaderChainValidator {
+    /// Start validating from a trusted `active_schedule`, with no head block yet.
+    pub fn new(active_schedule: ProducerScheduleV2) -> Self {
+        Self {
+            head_block_num: 0,
+            head_block_id: Checksum256::default(),
+            active_schedule,
+            pending_schedule: None,
+        }
+    }
+
+    /// The producer schedule currently used to verify signatures.
+    pub fn active_schedule(&self) -> &ProducerScheduleV2 {
+        &self.active_schedule
+    }
+
+    /// The most recently accepted block's id, or the zero hash before the first header.
+    pub fn head_block_id(&self) -> Checksum256 {
+        self.head_block_id
+    }
+
+    /// The most recently accepted block's number, or `0` before the first header.
+    pub fn head_block_num(&self) -> u32 {
+        self.head_block_num
+    }
+
+    /// Validate and fold `header` onto the chain, advancing the head and
+    /// rotating the producer schedule as needed.
+    pub fn push_header(&mut self, header: &SignedBlockHeader) -> Result<()> {
+        let block_num = header.block_num();
+
+        if self.head_block_num != 0 {
+            if block_num != self.head_block_num + 1
+                || header.block_header.previous != self.head_block_id
+            {
+                return Err(Error::BrokenParentLink);
+            }
+        }
+
+        // A header's own `schedule_version` names the schedule that was active
+        // when it was signed, so a pending schedule must take effect here,
+        // before `verify` below, not after: the first header produced under a
+        // rotated schedule needs to be checked against that new schedule, not
+        // the one it's superseding.
+        if let Some(pending) = self.pending_schedule.take() {
+            if header.block_header.schedule_version == pending.version {
+                self.active_schedule = pending;
+            } else {
+                self.pending_schedule = Some(pending);
+            }
+        }
+
+        header.verify(&self.active_schedule)?;
+
+        if let Some(new_producers) = &header.block_header.new_producers {
+            self.pending_schedule = Some(new_producers.clone());
+        }
+
+        self.head_block_num = block_num;
+        self.head_block_id = header.id()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use keys::public::PublicKey;
+    use primitives::{BlockHeader, ProducerKey, ProducerScheduleV2, Signature};
+    use secp256k1::{Message, Secp256k1, SecretKey};
+
+    use super::HeaderChainValidator;
+    use crate::Error;
+
+    const SIG: &str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+
+    fn header_with_schedule_version(version: u32) -> primitives::SignedBlockHeader {
+        primitives::SignedBlockHeader {
+            block_header: BlockHeader {
+                schedule_version: version,
+                ..Default::default()
+            },
+            producer_signature: Signature::from_str(SIG).unwrap(),
+        }
+    }
+
+    #[test]
+    fn push_header_errors_on_broken_parent_link() {
+        let mut validator = HeaderChainValidator {
+            head_block_num: 5,
+            head_block_id: Default::default(),
+            active_schedule: ProducerScheduleV2::default(),
+            pending_schedule: None,
+        };
+
+        let header = header_with_schedule_version(0);
+        let err = validator.push_header(&header).unwrap_err();
+
+        assert!(matches!(err, Error::BrokenParentLink));
+    }
+
+    #[test]
+    fn push_header_rotates_pending_schedule_before_verifying() {
+        let mut validator = HeaderChainValidator::new(ProducerScheduleV2::default());
+        validator.pending_schedule = Some(ProducerScheduleV2 {
+            version: 2,
+            producers: vec![],
+        });
+
+        let header = header_with_schedule_version(2);
+        // No producer in either schedule can match, so `verify` always fails,
+        // but the rotation must have already happened by the time it runs.
+        let result = validator.push_header(&header);
+
+        assert!(result.is_err());
+        assert_eq!(validator.active_schedule().version, 2);
+        assert!(validator.pending_schedule.is_none());
+    }
+
+    #[test]
+    fn push_header_accepts_a_genuinely_signed_header() {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secret_key);
+
+        let block_header = BlockHeader::default();
+        let digest = block_header.digest().unwrap();
+        let msg = Message::from_slice(digest.as_bytes()).unwrap();
+        let recoverable = secp.sign_recoverable(&msg, &secret_key);
+        let (recovery_id, rs) = recoverable.serialize_compact();
+
+        let mut compact = [0u8; 65];
+        compact[0] = 31 + recovery_id.to_i32() as u8;
+        compact[1..].copy_from_slice(&rs);
+
+        let schedule = ProducerScheduleV2 {
+            version: 0,
+            producers: vec![ProducerKey {
+                producer_name: Default::default(),
+                block_signing_key: PublicKey { compressed: true, key: public_key },
+            }],
+        };
+        let header = primitives::SignedBlockHeader {
+            block_header,
+            producer_signature: Signature::from_compact(compact),
+        };
+
+        let mut validator = HeaderChainValidator::new(schedule);
+        assert!(validator.push_header(&header).is_ok());
+        assert_eq!(validator.head_block_num(), header.block_num());
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{Checksum256, Error, Result};
+
+/// An incrementally-built Merkle accumulator, matching EOS consensus hashing.
+///
+/// Leaves are folded in one at a time via [`append`](IncrementalMerkle::append),
+/// keeping only the `O(log n)` nodes needed to produce the next root rather than
+/// the full tree. This is what a light client uses to validate the
+/// `transaction_mroot`/`action_mroot` stored in a `BlockHeader` without holding
+/// every transaction or action in memory.
+///
+/// `active_nodes` holds the real (unpadded) peak at each depth whose bit is set
+/// in `node_count`'s binary representation, ascending by depth, the same
+/// invariant a Merkle Mountain Range keeps. Folding in a new leaf is a binary
+/// counter increment: it carries into and merges away existing peaks from the
+/// bottom up, stopping at the first unset bit. The single EOS root is then a
+/// separate, always-recomputed "bagging" of those peaks that pads over any
+/// gaps between them by self-pairing, which is what lets a duplicated leaf at
+/// one append stop mattering the moment a real sibling arrives.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IncrementalMerkle {
+    node_count: u64,
+    active_nodes: Vec<Checksum256>,
+}
+
+impl IncrementalMerkle {
+    /// An empty accumulator with no leaves appended yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of leaves folded into this accumulator so far.
+    pub fn node_count(&self) -> u64 {
+        self.node_count
+    }
+
+    /// The minimal set of real (unpadded) peaks kept to compute the next root.
+    pub fn active_nodes(&self) -> &[Checksum256] {
+        &self.active_nodes
+    }
+
+    /// The current Merkle root, or the zero hash if no leaves have been appended.
+    pub fn root(&self) -> Result<Checksum256> {
+        let max_depth = calculate_max_depth(self.node_count);
+        let mut peaks = depth_tagged_peaks(self.node_count, &self.active_nodes);
+
+        let (mut acc_depth, mut acc) = match peaks.next() {
+            Some(first) => first,
+            None => return Ok(Checksum256::default()),
+        };
+
+        for (depth, peak) in peaks {
+            while acc_depth < depth {
+                acc = hash_canonical_pair(acc, acc)?;
+                acc_depth += 1;
+            }
+            acc = hash_canonical_pair(peak, acc)?;
+            acc_depth += 1;
+        }
+
+        while acc_depth + 1 < max_depth {
+            acc = hash_canonical_pair(acc, acc)?;
+            acc_depth += 1;
+        }
+
+        Ok(acc)
+    }
+
+    /// Fold `leaf` into the accumulator, returning the new root.
+    pub fn append(&mut self, leaf: Checksum256) -> Result<Checksum256> {
+        // Carry the new leaf into the existing peaks like a binary counter
+        // increment: merge away every peak at a depth whose bit is set in
+        // `node_count`, stopping the moment we reach an unset bit.
+        let mut carry = leaf;
+        let mut remaining = self.active_nodes.iter().copied();
+        let mut index = self.node_count;
+
+        while index & 0x1 == 1 {
+            let left = remaining.next().ok_or(Error::IncreMerkleError)?;
+            carry = hash_canonical_pair(left, carry)?;
+            index >>= 1;
+        }
+
+        let mut updated_active_nodes = Vec::with_capacity(self.active_nodes.len() + 1);
+        updated_active_nodes.push(carry);
+        updated_active_nodes.extend(remaining);
+
+        self.active_nodes = updated_active_nodes;
+        self.node_count += 1;
+
+        self.root()
+    }
+}
+
+/// `ceil(log2(num_nodes)) + 1`, the depth of the single padded root tree for
+/// an accumulator of `num_nodes` leaves.
+fn calculate_max_depth(num_nodes: u64) -> u64 {
+    if num_nodes == 0 {
+        return 0;
+    }
+
+    let mut value = num_nodes - 1;
+    let mut depth = 0u64;
+    while value > 0 {
+        value >>= 1;
+        depth += 1;
+    }
+
+    depth + 1
+}
+
+/// Pair up `active_nodes` with the depth each one lives at: depth `i` holds a
+/// real peak iff bit `i` of `node_count` is set, and `active_nodes` stores
+/// those peaks in the same ascending-depth order.
+fn depth_tagged_peaks(
+    node_count: u64,
+    active_nodes: &[Checksum256],
+) -> impl Iterator<Item = (u64, Checksum256)> + '_ {
+    let mut remaining = node_count;
+    let mut depth = 0u64;
+    let mut nodes = active_nodes.iter();
+
+    core::iter::from_fn(move || {
+        while remaining != 0 {
+            let is_peak = remaining & 0x1 == 1;
+            remaining >>= 1;
+            let this_depth = depth;
+            depth += 1;
+            if is_peak {
+                return nodes.next().map(|node| (this_depth, *node));
+            }
+        }
+        None
+    })
+}
+
+/// Mark `val` as the left operand of a pair about to be hashed, by clearing the
+/// high bit of the first byte of its `hash0` word.
+fn make_canonical_left(mut val: Checksum256) -> Checksum256 {
+    let hash0 = val.hash0() & 0xFFFFFFFFFFFFFF7F;
+    val.set_hash0(hash0);
+    val
+}
+
+/// Mark `val` as the right operand of a pair about to be hashed, by setting the
+/// high bit of the first byte of its `hash0` word.
+fn make_canonical_right(mut val: Checksum256) -> Checksum256 {
+    let hash0 = val.hash0() | 0x80;
+    val.set_hash0(hash0);
+    val
+}
+
+/// Canonicalize `left`/`right` and hash them as a pair, the way EOS combines
+/// two Merkle nodes into their parent.
+fn hash_canonical_pair(left: Checksum256, right: Checksum256) -> Result<Checksum256> {
+    Checksum256::hash((make_canonical_left(left), make_canonical_right(right)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::IncrementalMerkle;
+    use crate::Checksum256;
+
+    /// Reference root: pad the leaf list with a duplicate of its last element
+    /// at every level until one node remains, the textbook definition the
+    /// incremental algorithm above is an `O(log n)`-state shortcut for.
+    fn naive_root(leaves: &[Checksum256]) -> Checksum256 {
+        let mut level = leaves.to_vec();
+        while level.len() > 1 {
+            if level.len() % 2 == 1 {
+                let last = *level.last().unwrap();
+                level.push(last);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| super::hash_canonical_pair(pair[0], pair[1]).unwrap())
+                .collect();
+        }
+        level[0]
+    }
+
+    #[test]
+    fn append_single_leaf_roots_to_itself() {
+        let mut merkle = IncrementalMerkle::new();
+        let leaf = Checksum256::hash(1u64).unwrap();
+
+        let root = merkle.append(leaf).unwrap();
+
+        assert_eq!(root, leaf);
+        assert_eq!(merkle.node_count(), 1);
+    }
+
+    #[test]
+    fn append_two_leaves_roots_to_their_pair_hash() {
+        let mut merkle = IncrementalMerkle::new();
+        let leaf1 = Checksum256::hash(1u64).unwrap();
+        let leaf2 = Checksum256::hash(2u64).unwrap();
+
+        merkle.append(leaf1).unwrap();
+        let root = merkle.append(leaf2).unwrap();
+
+        assert_eq!(merkle.node_count(), 2);
+        assert_eq!(root, super::hash_canonical_pair(leaf1, leaf2).unwrap());
+        assert_ne!(root, leaf1);
+        assert_ne!(root, leaf2);
+    }
+
+    #[test]
+    fn append_is_order_sensitive() {
+        let mut forward = IncrementalMerkle::new();
+        let mut backward = IncrementalMerkle::new();
+        let leaf1 = Checksum256::hash(1u64).unwrap();
+        let leaf2 = Checksum256::hash(2u64).unwrap();
+
+        forward.append(leaf1).unwrap();
+        let forward_root = forward.append(leaf2).unwrap();
+
+        backward.append(leaf2).unwrap();
+        let backward_root = backward.append(leaf1).unwrap();
+
+        assert_ne!(forward_root, backward_root);
+    }
+
+    #[test]
+    fn append_matches_naive_root_across_a_power_of_two_boundary() {
+        let leaves: Vec<Checksum256> = (1..=20u64)
+            .map(|i| Checksum256::hash(i).unwrap())
+            .collect();
+
+        let mut merkle = IncrementalMerkle::new();
+        for (n, leaf) in leaves.iter().enumerate() {
+            let root = merkle.append(*leaf).unwrap();
+            assert_eq!(root, naive_root(&leaves[..=n]), "mismatch at {} leaves", n + 1);
+        }
+    }
+}
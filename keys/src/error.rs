@@ -0,0 +1,24 @@
+use crate::base58;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    Base58(base58::Error),
+    Secp256k1(secp256k1::Error),
+    /// A key or signature names a curve this crate doesn't implement, e.g. a
+    /// `PUB_R1_`/secp256r1 public key.
+    UnsupportedCurve,
+}
+
+impl From<base58::Error> for Error {
+    fn from(err: base58::Error) -> Self {
+        Error::Base58(err)
+    }
+}
+
+impl From<secp256k1::Error> for Error {
+    fn from(err: secp256k1::Error) -> Self {
+        Error::Secp256k1(err)
+    }
+}
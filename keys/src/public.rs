@@ -1,4 +1,18 @@
+// `no-std` must be declared in this crate's `[features]` with `core2` and
+// `alloc` (via the crate root's `extern crate alloc;`) as its dependencies
+// for this `cfg` to ever be enabled.
+#[cfg(not(feature = "no-std"))]
 use std::{fmt, io, str::FromStr};
+#[cfg(feature = "no-std")]
+use core::{fmt, str::FromStr};
+#[cfg(feature = "no-std")]
+use core2::io;
+
+#[cfg(feature = "no-std")]
+use alloc::{format, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{de, ser};
 
 use bitcoin_hashes::{Hash as HashTrait, sha256};
 use byteorder::{ByteOrder, LittleEndian};
@@ -38,7 +52,7 @@ impl PublicKey {
         buf
     }
 
-    /// Serialize the public key to Eos format string
+    /// Serialize the public key to the legacy `EOS`-prefixed Eos format string
     pub fn to_eos_fmt(&self) -> String {
         let h160 = hash::ripemd160(&self.key.serialize());
         let mut public_key = [0u8; PUBLIC_KEY_WITH_CHECKSUM_SIZE];
@@ -48,6 +62,19 @@ impl PublicKey {
         format!("EOS{}", base58::encode_slice(&public_key))
     }
 
+    /// Serialize the public key to the modern type-tagged `PUB_K1_` Eos format string
+    pub fn to_eos_new_fmt(&self) -> String {
+        let mut type_suffixed = self.key.serialize().to_vec();
+        type_suffixed.extend_from_slice(b"K1");
+        let checksum = hash::ripemd160(&type_suffixed);
+
+        let mut public_key = [0u8; PUBLIC_KEY_WITH_CHECKSUM_SIZE];
+        public_key[..PUBLIC_KEY_SIZE].copy_from_slice(self.to_bytes().as_ref());
+        public_key[PUBLIC_KEY_SIZE..].copy_from_slice(&checksum.take()[..PUBLIC_KEY_CHECKSUM_SIZE]);
+
+        format!("PUB_K1_{}", base58::encode_slice(&public_key))
+    }
+
     /// Verify a signature on a message with public key.
     pub fn verify(&self, message_slice: &[u8], signature: &Signature) -> crate::Result<()> {
         let msg_hash = sha256::Hash::hash(&message_slice);
@@ -58,7 +85,7 @@ impl PublicKey {
     pub fn verify_hash(&self, hash: &[u8], signature: &Signature) -> crate::Result<()> {
         let secp = Secp256k1::verification_only();
         let msg = Message::from_slice(&hash).unwrap();
-        secp.verify(&msg, &signature.to_standard(), &self.key)?;
+        secp.verify(&msg, &signature.to_standard()?, &self.key)?;
 
         Ok(())
     }
@@ -95,29 +122,108 @@ impl fmt::Display for PublicKey {
 impl FromStr for PublicKey {
     type Err = error::Error;
     fn from_str(s: &str) -> crate::Result<PublicKey> {
-        if !s.starts_with("EOS") {
+        // `PUB_R1_` names a secp256r1 (R1) key, but this crate only
+        // implements secp256k1 (K1) point arithmetic. We still parse and
+        // checksum the string so a well-formed `PUB_R1_` key is rejected for
+        // the right reason below (an unsupported curve), rather than relying
+        // on `secp256k1::PublicKey::from_slice` to reject the raw bytes: an
+        // R1 point's x-coordinate satisfies the (unrelated) secp256k1 curve
+        // equation about half the time, so that would silently accept many
+        // genuine R1 keys as bogus K1 ones.
+        let (payload, suffix, is_r1) = if let Some(stripped) = s.strip_prefix("PUB_K1_") {
+            (stripped, Some(&b"K1"[..]), false)
+        } else if let Some(stripped) = s.strip_prefix("PUB_R1_") {
+            (stripped, Some(&b"R1"[..]), true)
+        } else if let Some(stripped) = s.strip_prefix("EOS") {
+            (stripped, None, false)
+        } else {
             return Err(secp256k1::Error::InvalidPublicKey.into());
-        }
+        };
 
-        let s_hex = base58::from(&s[3..])?;
+        let s_hex = base58::from(payload)?;
         if s_hex.len() != PUBLIC_KEY_WITH_CHECKSUM_SIZE {
             return Err(secp256k1::Error::InvalidPublicKey.into());
         }
         let raw = &s_hex[..PUBLIC_KEY_SIZE];
 
-        // Verify checksum
-        let expected = LittleEndian::read_u32(&hash::ripemd160(raw)[..4]);
+        // Verify checksum. The type-tagged `PUB_K1_`/`PUB_R1_` forms key the
+        // checksum on the curve suffix; the legacy `EOS` form does not.
+        let checksum_hash = match suffix {
+            Some(suffix) => {
+                let mut type_suffixed = raw.to_vec();
+                type_suffixed.extend_from_slice(suffix);
+                hash::ripemd160(&type_suffixed)
+            }
+            None => hash::ripemd160(raw),
+        };
+        let expected = LittleEndian::read_u32(&checksum_hash[..PUBLIC_KEY_CHECKSUM_SIZE]);
         let actual = LittleEndian::read_u32(&s_hex[PUBLIC_KEY_SIZE..PUBLIC_KEY_WITH_CHECKSUM_SIZE]);
         if expected != actual {
             return Err(base58::Error::BadChecksum(expected, actual).into());
         }
 
+        if is_r1 {
+            return Err(error::Error::UnsupportedCurve);
+        }
+
         let key = secp256k1::PublicKey::from_slice(&raw)?;
 
         Ok(PublicKey { key, compressed: true })
     }
 }
 
+/// Serializes as the `EOS...` string for human-readable formats, matching
+/// nodeos's JSON, and as raw key bytes otherwise.
+#[cfg(feature = "std")]
+impl ser::Serialize for PublicKey {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_eos_fmt())
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> de::Deserialize<'de> for PublicKey {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            struct EosStrVisitor;
+
+            impl<'de> de::Visitor<'de> for EosStrVisitor {
+                type Value = PublicKey;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("an EOS public key string")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                    PublicKey::from_str(v).map_err(|e| E::custom(format!("{:?}", e)))
+                }
+            }
+
+            deserializer.deserialize_str(EosStrVisitor)
+        } else {
+            struct BytesVisitor;
+
+            impl<'de> de::Visitor<'de> for BytesVisitor {
+                type Value = PublicKey;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("public key bytes")
+                }
+
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                    PublicKey::from_slice(v).map_err(|e| E::custom(format!("{:?}", e)))
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
 impl<'a> From<&'a SecretKey> for PublicKey {
     /// Derive this public key from its corresponding `SecretKey`.
     fn from(sk: &SecretKey) -> PublicKey {
@@ -134,10 +240,7 @@ impl<'a> From<&'a SecretKey> for PublicKey {
 mod test {
     use std::str::FromStr;
 
-    use secp256k1::Error::IncorrectSignature;
-
     use crate::error;
-    use crate::error::Error::Secp256k1;
     use crate::signature::Signature;
 
     use super::PublicKey;
@@ -150,6 +253,36 @@ mod test {
         assert_eq!(pk.unwrap().to_string(), pk_str);
     }
 
+    #[test]
+    fn pk_from_str_new_fmt_should_work() {
+        let pk_str = "PUB_K1_8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMD9aL1";
+        let pk = PublicKey::from_str(pk_str);
+        assert!(pk.is_ok());
+        assert_eq!(pk.unwrap().to_eos_new_fmt(), pk_str);
+    }
+
+    #[test]
+    fn pk_from_str_r1_fmt_is_not_yet_supported() {
+        // A checksum-valid `PUB_R1_` string whose raw bytes happen to also
+        // satisfy the (unrelated) secp256k1 curve equation: the rejection
+        // must come from the explicit curve check, not from
+        // `secp256k1::PublicKey::from_slice` accidentally failing, or this
+        // key would otherwise be silently accepted as a bogus K1 key.
+        let pk_str = "PUB_R1_8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuLfoWwL";
+        let pk = PublicKey::from_str(pk_str);
+        assert_eq!(pk.unwrap_err(), error::Error::UnsupportedCurve);
+    }
+
+    #[test]
+    fn pk_serde_json_round_trips_as_eos_string() {
+        let pk_str = "EOS8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMxKhAm";
+        let pk = PublicKey::from_str(pk_str).unwrap();
+
+        let json = serde_json::to_string(&pk).unwrap();
+        assert_eq!(json, format!("\"{}\"", pk_str));
+        assert_eq!(serde_json::from_str::<PublicKey>(&json).unwrap(), pk);
+    }
+
     #[test]
     fn pk_from_str_should_error() {
         let pk_str = "8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMxKhAm";
@@ -159,31 +292,49 @@ mod test {
     }
 
     #[test]
-    fn pk_verify_should_work() {
+    fn pk_verify_recovers_the_signing_key() {
         let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
         let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
 
-        let pk = PublicKey::from_str(pk_str);
-        assert!(pk.is_ok());
-        let sig = Signature::from_str(sig_str);
-        assert!(sig.is_ok());
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
 
-        let vfy = pk.unwrap().verify("hello".as_bytes(), &sig.unwrap());
-        assert!(vfy.is_ok());
+        let recovered = sig.recover("hello".as_bytes());
+        assert!(recovered.is_ok());
+        assert_eq!(recovered.unwrap(), pk);
     }
 
     #[test]
-    fn pk_verify_should_error() {
+    fn pk_verify_does_not_recover_mismatched_key() {
         let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
         let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
 
-        let pk = PublicKey::from_str(pk_str);
-        assert!(pk.is_ok());
-        let sig = Signature::from_str(sig_str);
-        assert!(sig.is_ok());
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
+
+        let recovered = sig.recover("world".as_bytes()).unwrap();
+        assert_ne!(recovered, pk);
+    }
+
+    #[test]
+    fn pk_verify_accepts_a_matching_signature() {
+        let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
+        let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
+
+        assert!(pk.verify("hello".as_bytes(), &sig).is_ok());
+    }
+
+    #[test]
+    fn pk_verify_rejects_a_mismatched_signature() {
+        let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
+        let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
 
-        let vfy = pk.unwrap().verify("world".as_bytes(), &sig.unwrap());
-        assert!(vfy.is_err());
-        assert_eq!(vfy, Err(Secp256k1(IncorrectSignature)));
+        assert!(pk.verify("world".as_bytes(), &sig).is_err());
     }
 }
@@ -0,0 +1,225 @@
+// See the matching `cfg` in `public.rs` for the `[features]`/dependency
+// wiring this crate still needs before this can be enabled.
+#[cfg(not(feature = "no-std"))]
+use std::{fmt, str::FromStr};
+#[cfg(feature = "no-std")]
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "no-std")]
+use alloc::{format, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{de, ser};
+
+use bitcoin_hashes::{Hash as HashTrait, sha256};
+use byteorder::{ByteOrder, LittleEndian};
+use secp256k1::recovery::{RecoverableSignature, RecoveryId};
+use secp256k1::{self, Message, Secp256k1};
+
+use crate::{error, hash};
+use crate::base58;
+use crate::public::PublicKey;
+
+/// Size of an EOS compact signature: a 1-byte recovery header plus `r || s`
+const SIGNATURE_SIZE: usize = 65;
+/// Size of the checksum appended to a signature string
+const SIGNATURE_CHECKSUM_SIZE: usize = 4;
+/// Size of a signature string's payload, signature plus checksum
+const SIGNATURE_WITH_CHECKSUM_SIZE: usize = SIGNATURE_SIZE + SIGNATURE_CHECKSUM_SIZE;
+
+/// A Secp256k1 signature, recoverable to the public key that produced it
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Signature {
+    compact: [u8; SIGNATURE_SIZE],
+}
+
+impl Signature {
+    /// Wrap a raw EOS compact signature (1-byte recovery header plus `r || s`).
+    pub fn from_compact(compact: [u8; SIGNATURE_SIZE]) -> Signature {
+        Signature { compact }
+    }
+
+    /// The recovery id EOS packs into the header byte of a compact signature.
+    ///
+    /// EOS always signs with compressed keys, so the header is `31 + recid`
+    /// rather than bitcoin's `27 + recid` (`+4` for compressed keys).
+    fn recovery_id(&self) -> crate::Result<RecoveryId> {
+        let header = i32::from(self.compact[0]) - 31;
+        Ok(RecoveryId::from_i32(header)?)
+    }
+
+    fn to_recoverable(&self) -> crate::Result<RecoverableSignature> {
+        Ok(RecoverableSignature::from_compact(&self.compact[1..], self.recovery_id()?)?)
+    }
+
+    /// Serialize the signature to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.compact.to_vec()
+    }
+
+    /// Convert to a standard, non-recoverable signature, the form `PublicKey::verify_hash` checks.
+    pub fn to_standard(&self) -> crate::Result<secp256k1::Signature> {
+        Ok(self.to_recoverable()?.to_standard())
+    }
+
+    /// Recover the public key that produced this signature over `message`.
+    pub fn recover(&self, message: &[u8]) -> crate::Result<PublicKey> {
+        let msg_hash = sha256::Hash::hash(message);
+        self.recover_hash(&msg_hash)
+    }
+
+    /// Recover the public key that produced this signature over `hash`.
+    pub fn recover_hash(&self, hash: &[u8]) -> crate::Result<PublicKey> {
+        let secp = Secp256k1::verification_only();
+        let msg = Message::from_slice(&hash)?;
+        let key = secp.recover(&msg, &self.to_recoverable()?)?;
+
+        Ok(PublicKey { compressed: true, key })
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut type_suffixed = self.compact.to_vec();
+        type_suffixed.extend_from_slice(b"K1");
+        let checksum = hash::ripemd160(&type_suffixed);
+
+        let mut sig = [0u8; SIGNATURE_WITH_CHECKSUM_SIZE];
+        sig[..SIGNATURE_SIZE].copy_from_slice(&self.compact);
+        sig[SIGNATURE_SIZE..].copy_from_slice(&checksum.take()[..SIGNATURE_CHECKSUM_SIZE]);
+
+        write!(f, "SIG_K1_{}", base58::encode_slice(&sig))
+    }
+}
+
+impl FromStr for Signature {
+    type Err = error::Error;
+    fn from_str(s: &str) -> crate::Result<Signature> {
+        if !s.starts_with("SIG_K1_") {
+            return Err(secp256k1::Error::InvalidSignature.into());
+        }
+
+        let s_hex = base58::from(&s[7..])?;
+        if s_hex.len() != SIGNATURE_WITH_CHECKSUM_SIZE {
+            return Err(secp256k1::Error::InvalidSignature.into());
+        }
+        let raw = &s_hex[..SIGNATURE_SIZE];
+
+        // Verify checksum, keyed on the "K1" curve suffix
+        let mut type_suffixed = raw.to_vec();
+        type_suffixed.extend_from_slice(b"K1");
+        let expected = LittleEndian::read_u32(&hash::ripemd160(&type_suffixed)[..SIGNATURE_CHECKSUM_SIZE]);
+        let actual = LittleEndian::read_u32(&s_hex[SIGNATURE_SIZE..SIGNATURE_WITH_CHECKSUM_SIZE]);
+        if expected != actual {
+            return Err(base58::Error::BadChecksum(expected, actual).into());
+        }
+
+        let mut compact = [0u8; SIGNATURE_SIZE];
+        compact.copy_from_slice(raw);
+
+        Ok(Signature { compact })
+    }
+}
+
+/// Serializes as the `SIG_K1_...` string for human-readable formats (matching
+/// nodeos's JSON), and as the raw compact signature bytes for binary formats,
+/// mirroring how `rust-bitcoin` serializes its signature types.
+#[cfg(feature = "std")]
+impl ser::Serialize for Signature {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> de::Deserialize<'de> for Signature {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            struct SigStrVisitor;
+
+            impl<'de> de::Visitor<'de> for SigStrVisitor {
+                type Value = Signature;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("an EOS signature string")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                    Signature::from_str(v).map_err(|e| E::custom(format!("{:?}", e)))
+                }
+            }
+
+            deserializer.deserialize_str(SigStrVisitor)
+        } else {
+            struct BytesVisitor;
+
+            impl<'de> de::Visitor<'de> for BytesVisitor {
+                type Value = Signature;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("compact signature bytes")
+                }
+
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                    if v.len() != SIGNATURE_SIZE {
+                        return Err(E::invalid_length(v.len(), &self));
+                    }
+
+                    let mut compact = [0u8; SIGNATURE_SIZE];
+                    compact.copy_from_slice(v);
+
+                    Ok(Signature { compact })
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Signature;
+    use crate::public::PublicKey;
+
+    #[test]
+    fn sig_recover_should_work() {
+        let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
+        let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
+
+        let recovered = sig.recover("hello".as_bytes());
+        assert!(recovered.is_ok());
+        assert_eq!(recovered.unwrap(), pk);
+    }
+
+    #[test]
+    fn sig_serde_json_round_trips_as_eos_string() {
+        let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+        let sig = Signature::from_str(sig_str).unwrap();
+
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!("\"{}\"", sig_str));
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), sig);
+    }
+
+    #[test]
+    fn sig_recover_should_not_match_on_mismatched_message() {
+        let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
+        let sig_str = "SIG_K1_KomV6FEHKdtZxGDwhwSubEAcJ7VhtUQpEt5P6iDz33ic936aSXx87B2L56C8JLQkqNpp1W8ZXjrKiLHUEB4LCGeXvbtVuR";
+
+        let pk = PublicKey::from_str(pk_str).unwrap();
+        let sig = Signature::from_str(sig_str).unwrap();
+
+        let recovered = sig.recover("world".as_bytes()).unwrap();
+        assert_ne!(recovered, pk);
+    }
+}
@@ -133,6 +133,17 @@ impl SignedBlockHeader {
     pub fn block_num(&self) -> u32 {
         self.block_header.block_num()
     }
+
+    /// Verify `producer_signature` against the signing key `producer` holds in `schedule`.
+    pub fn verify(&self, schedule: &ProducerScheduleV2) -> crate::Result<()> {
+        let producer_key = schedule.producers.iter()
+            .find(|key| key.producer_name == self.block_header.producer)
+            .ok_or(crate::Error::UnknownProducer)?;
+
+        producer_key.block_signing_key
+            .verify_hash(self.block_header.digest()?.as_bytes(), &self.producer_signature)
+            .map_err(crate::Error::Keys)
+    }
 }
 
 impl core::fmt::Display for SignedBlockHeader {
@@ -0,0 +1,15 @@
+use keys::error as KeyError;
+
+use crate::{ReadError, WriteError};
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Clone, Debug)]
+pub enum Error {
+    BytesReadError(ReadError),
+    BytesWriteError(WriteError),
+    Keys(KeyError::Error),
+    /// `SignedBlockHeader::verify` couldn't find the header's producer in the
+    /// schedule it was asked to verify against.
+    UnknownProducer,
+}